@@ -4,10 +4,10 @@
 //! ```rust,no_run,ignore-apple,ignore-windows
 //! use preempt_rt::sched;
 //! use preempt_rt::sched::{Pid, Scheduler, SchedulerParams};
-//! let sched = sched::get_scheduler(Pid::current_thread()).unwrap();
+//! let (sched, reset_on_fork) = sched::get_scheduler(Pid::current_thread()).unwrap();
 //! sched::set_scheduler(Pid::current_thread(), Scheduler::SCHED_FIFO, SchedulerParams {
 //!     priority: 80
-//! }).expect("failed to set scheduler");
+//! }, false).expect("failed to set scheduler");
 //! ```
 //!
 //! The `thread` module has wrappers around `thread::spawn` for creating threads with a given
@@ -28,14 +28,19 @@
 //! });
 //! ```
 //!
-//! Only SCHED_FIFO and SCHED_RR are meaningfully supported at the moment - SCHED_DEADLINE
-//! requires additional parameters to be set, but on most platforms the Rust libc bindings don't
-//! allow setting the additional attributes necessary to make this work, and the
-//! `libc::sched_setattr` function has to be used to change the values rather than
-//! `libc::sched_setscheduler`.
+//! SCHED_FIFO, SCHED_RR and SCHED_DEADLINE are all supported. SCHED_DEADLINE requires
+//! additional parameters the Rust libc bindings have no room for in `libc::sched_param`, so it
+//! is set via [`sched::DeadlineParams`] and `libc::syscall(libc::SYS_sched_setattr, ..)` rather
+//! than `libc::sched_setscheduler`.
+//!
+//! On macOS, SCHED_FIFO/SCHED_RR are backed by Mach's `THREAD_TIME_CONSTRAINT_POLICY` via
+//! `thread_policy_set`, the closest equivalent to Linux realtime scheduling on Apple platforms -
+//! a priority is translated into a nominal time-constraint policy, or [`sched::TimeConstraintParams`]
+//! can be supplied directly for precise control. SCHED_DEADLINE has no macOS equivalent and is
+//! not supported there.
 //!
 //! This crate also includes stub methods for `preempt_rt::thread::try_spawn` that compile on
-//! Windows and macOS (but does not attempt to set a scheduler). This is useful for
-//! building/running tests on non-linux platforms.
+//! Windows (but does not attempt to set a scheduler). This is useful for building/running tests
+//! on non-linux platforms.
 pub mod sched;
 pub mod thread;
@@ -1,5 +1,8 @@
-use crate::sched::PreemptRtError::{PriorityAboveMax, PriorityBelowMin};
+use crate::sched::PreemptRtError::{
+    InvalidDeadlineParams, NiceAboveMax, NiceBelowMin, PriorityAboveMax, PriorityBelowMin,
+};
 use libc::c_int;
+use std::time::Duration;
 use thiserror::Error;
 
 /// PreemptRt result type
@@ -16,8 +19,18 @@ pub enum PreemptRtError {
     PriorityAboveMax(c_int, c_int),
     #[error("priority {0} is lower than min priority {1}")]
     PriorityBelowMin(c_int, c_int),
+    #[error("nice value {0} is higher than max nice value {1}")]
+    NiceAboveMax(c_int, c_int),
+    #[error("nice value {0} is lower than min nice value {1}")]
+    NiceBelowMin(c_int, c_int),
     #[error("current platform {0} does not support preempt-rt")]
     NonLinuxPlatform(&'static str),
+    #[error("invalid deadline params: runtime must be <= deadline <= period")]
+    InvalidDeadlineParams,
+    #[error("{0:?} has no numeric priority range; use DeadlineParams or TimeConstraintParams directly instead of Priority")]
+    UnsupportedPriority(Scheduler),
+    #[error("macOS scheduling can only target the calling thread via mach_thread_self(); pid {0} is not the current thread")]
+    CannotTargetOtherThread(c_int),
 }
 
 #[cfg(not(target_os = "windows"))]
@@ -37,6 +50,21 @@ impl Pid {
     pub const fn current_thread() -> Self {
         Pid(0)
     }
+
+    /// Wrap an arbitrary raw pid or tid, e.g. one belonging to another process, or one read
+    /// from `/proc/<pid>/task/`.
+    #[cfg(not(target_os = "windows"))]
+    pub const fn from_raw(pid: libc::pid_t) -> Self {
+        Pid(pid)
+    }
+
+    /// Wrap an arbitrary raw pid or tid, e.g. one belonging to another process.
+    ///
+    /// Not meaningful on windows, where this crate has no concept of a real pid.
+    #[cfg(target_os = "windows")]
+    pub const fn from_raw(pid: i32) -> Self {
+        Pid(pid)
+    }
 }
 
 #[cfg(not(target_os = "windows"))]
@@ -121,13 +149,30 @@ impl TryFrom<c_int> for Scheduler {
     }
 }
 
+#[cfg(not(target_os = "windows"))]
+fn last_errno() -> c_int {
+    #[cfg(target_os = "linux")]
+    return unsafe { *libc::__errno_location() };
+    #[cfg(target_os = "macos")]
+    return unsafe { *libc::__error() };
+}
+
+#[cfg(not(target_os = "windows"))]
+fn clear_errno() {
+    #[cfg(target_os = "linux")]
+    unsafe {
+        *libc::__errno_location() = 0
+    };
+    #[cfg(target_os = "macos")]
+    unsafe {
+        *libc::__error() = 0
+    };
+}
+
 #[cfg(not(target_os = "windows"))]
 fn handle_errno(result: c_int) -> RtResult<c_int> {
     if result == -1 {
-        #[cfg(target_os = "linux")]
-        return Err(PreemptRtError::Errno(unsafe { *libc::__errno_location() }));
-        #[cfg(target_os = "macos")]
-        return Err(PreemptRtError::Errno(unsafe { *libc::__error() }));
+        Err(PreemptRtError::Errno(last_errno()))
     } else {
         Ok(result)
     }
@@ -147,11 +192,12 @@ impl Scheduler {
         handle_errno(res)
     }
 
-    /// Create a ParameterizedScheduler with the given priority.
-    pub fn with_params(self, params: SchedulerParams) -> ParameterizedScheduler {
+    /// Create a ParameterizedScheduler with the given params.
+    pub fn with_params(self, params: SchedParams) -> ParameterizedScheduler {
         ParameterizedScheduler {
             scheduler: self,
             params,
+            reset_on_fork: false,
         }
     }
 }
@@ -172,17 +218,72 @@ impl Scheduler {
         Err(PreemptRtError::NonLinuxPlatform("windows"))
     }
 
-    /// Create a ParameterizedScheduler with the given priority.
+    /// Create a ParameterizedScheduler with the given params.
     ///
     /// Returns 0 value on windows.
-    pub fn with_params(self, params: SchedulerParams) -> ParameterizedScheduler {
+    pub fn with_params(self, params: SchedParams) -> ParameterizedScheduler {
         ParameterizedScheduler {
             scheduler: Scheduler::SCHED_WINDOWS,
             params,
+            reset_on_fork: false,
         }
     }
 }
 
+#[cfg(not(target_os = "windows"))]
+/// Set the nice value for a given process or thread via `setpriority(PRIO_PROCESS, ..)`.
+/// Using `Pid::from_raw(0)` will set the nice value for the calling thread.
+///
+/// `SCHED_NORMAL`, `SCHED_BATCH` and `SCHED_IDLE` only accept a `sched_priority` of `0`, so the
+/// nice value is the only way to influence their scheduling - e.g. pairing `SCHED_BATCH` with a
+/// positive nice value to de-prioritize a CPU-bound background thread. Valid range is `[-20,
+/// 19]` inclusive; lower values run at a higher priority.
+pub fn set_nice(pid: Pid, nice: c_int) -> RtResult<()> {
+    if nice > 19 {
+        return Err(NiceAboveMax(nice, 19));
+    } else if nice < -20 {
+        return Err(NiceBelowMin(nice, -20));
+    }
+
+    let pid: libc::pid_t = pid.into();
+    let res = unsafe { libc::setpriority(libc::PRIO_PROCESS, pid as libc::id_t, nice) };
+    handle_errno(res).map(drop)
+}
+
+#[cfg(not(target_os = "windows"))]
+/// Get the nice value for a given process or thread via `getpriority(PRIO_PROCESS, ..)`.
+/// Using `Pid::from_raw(0)` will return the nice value for the calling thread.
+///
+/// Unlike this crate's other libc wrappers, `getpriority` can legitimately return `-1`, so
+/// errno is cleared beforehand and checked explicitly rather than relying on the return value
+/// alone to signal an error.
+pub fn get_nice(pid: Pid) -> RtResult<c_int> {
+    clear_errno();
+    let raw_pid: libc::pid_t = pid.into();
+    let res = unsafe { libc::getpriority(libc::PRIO_PROCESS, raw_pid as libc::id_t) };
+    if res == -1 && last_errno() != 0 {
+        Err(PreemptRtError::Errno(last_errno()))
+    } else {
+        Ok(res)
+    }
+}
+
+#[cfg(target_os = "windows")]
+/// Set the nice value for a given process or thread.
+///
+/// Returns an error on windows.
+pub fn set_nice(_pid: Pid, _nice: c_int) -> RtResult<()> {
+    Err(PreemptRtError::NonLinuxPlatform("windows"))
+}
+
+#[cfg(target_os = "windows")]
+/// Get the nice value for a given process or thread.
+///
+/// Returns an error on windows.
+pub fn get_nice(_pid: Pid) -> RtResult<c_int> {
+    Err(PreemptRtError::NonLinuxPlatform("windows"))
+}
+
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 /// Schedule parameters for a thread. Priority is the only supported parameter by the kernel
@@ -228,29 +329,195 @@ impl From<libc::sched_param> for SchedulerParams {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// Schedule parameters for the Linux deadline scheduler (`SCHED_DEADLINE`). The kernel tracks
+/// `runtime`, `deadline` and `period` as nanosecond counts in its `struct sched_attr`; this type
+/// stores them as [`Duration`]s and converts on the way in/out of the syscall.
+///
+/// `sched_priority` is always `0` for `SCHED_DEADLINE`, so it isn't part of this type - the
+/// kernel requires it and [`ParameterizedScheduler::set_on`] sends it as such.
+pub struct DeadlineParams {
+    /// Maximum amount of CPU time reserved for this task in each period.
+    pub runtime: Duration,
+    /// Relative deadline by which `runtime` must have been consumed.
+    pub deadline: Duration,
+    /// Length of one scheduling period, after which the runtime budget is replenished.
+    pub period: Duration,
+}
+
+impl DeadlineParams {
+    /// The kernel requires `runtime <= deadline <= period`; reject anything else up front rather
+    /// than letting `sched_setattr` fail with an opaque `EINVAL`.
+    fn validate(&self) -> RtResult<()> {
+        if self.runtime <= self.deadline && self.deadline <= self.period {
+            Ok(())
+        } else {
+            Err(InvalidDeadlineParams)
+        }
+    }
+}
+
+impl IntoSchedParams for DeadlineParams {
+    fn into_sched_params(self, _scheduler: Scheduler) -> SchedParams {
+        SchedParams::Deadline(self)
+    }
+}
+
+#[cfg(target_os = "macos")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// Parameters for macOS's Mach `THREAD_TIME_CONSTRAINT_POLICY`, the closest equivalent to
+/// Linux's SCHED_FIFO/SCHED_RR realtime scheduling on Apple platforms. Applied via
+/// `thread_policy_set` on the current Mach thread (`mach_thread_self()`); durations are
+/// converted to Mach absolute-time ticks with `mach_timebase_info`.
+pub struct TimeConstraintParams {
+    /// Nominal length of the computation's periodic cycle.
+    pub period: Duration,
+    /// Nominal amount of computation time needed within each period.
+    pub computation: Duration,
+    /// Maximum time that may elapse from the start of a period before its computation must
+    /// complete.
+    pub constraint: Duration,
+    /// Whether the thread may be scheduled outside of its computation time (i.e. is
+    /// preemptible).
+    pub preemptible: bool,
+}
+
+#[cfg(target_os = "macos")]
+impl IntoSchedParams for TimeConstraintParams {
+    fn into_sched_params(self, _scheduler: Scheduler) -> SchedParams {
+        SchedParams::TimeConstraint(self)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// A coarse, platform-independent priority level for use with [`Priority::Crossplatform`].
+pub enum CrossplatformPriority {
+    Lowest,
+    Low,
+    Normal,
+    High,
+    Highest,
+}
+
+impl CrossplatformPriority {
+    fn normalized(self) -> f32 {
+        match self {
+            CrossplatformPriority::Lowest => 0.0,
+            CrossplatformPriority::Low => 0.25,
+            CrossplatformPriority::Normal => 0.5,
+            CrossplatformPriority::High => 0.75,
+            CrossplatformPriority::Highest => 1.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// A scheduler priority expressed relative to the chosen [`Scheduler`]'s valid range rather than
+/// as a hardcoded integer. Priority ranges differ sharply per platform - Linux realtime is
+/// `[1, 99]`, FreeBSD is `[0, 31]`, macOS has no numeric realtime priority at all - so a
+/// hardcoded `80` tuned on one platform is meaningless on another.
+///
+/// Resolving a `Priority` requires a scheduler with a meaningful numeric range: it returns
+/// [`PreemptRtError::UnsupportedPriority`] for `SCHED_DEADLINE`, which has no `sched_priority`
+/// concept, and [`PreemptRtError::NonLinuxPlatform`] on Windows. Use [`DeadlineParams`]
+/// directly for `SCHED_DEADLINE`; on Windows a plain integer priority fails the same way.
+///
+/// ```rust,no_run,ignore-apple,ignore-windows
+/// use preempt_rt::sched::{Priority, Scheduler};
+/// use preempt_rt::thread;
+/// thread::spawn(Scheduler::SCHED_FIFO, Priority::Normalized(0.8), || {});
+/// ```
+pub enum Priority {
+    /// A priority in `0.0..=1.0`, linearly mapped onto the chosen scheduler's
+    /// `priority_min()..=priority_max()` range.
+    Normalized(f32),
+    /// A coarse, platform-independent priority level.
+    Crossplatform(CrossplatformPriority),
+}
+
+impl Priority {
+    /// Resolve this relative priority into a concrete [`SchedulerParams`] for `scheduler`, by
+    /// linearly mapping it onto `scheduler.priority_min()..=scheduler.priority_max()`. Deferred
+    /// until [`ParameterizedScheduler::set_on`] rather than done in [`IntoSchedParams`], since it
+    /// needs a scheduler with a meaningful numeric priority range - not the case on Windows
+    /// (where `priority_min`/`priority_max` always error) or for `SCHED_DEADLINE` (which has no
+    /// `sched_priority` concept at all and takes [`DeadlineParams`] instead).
+    fn resolve(self, scheduler: Scheduler) -> RtResult<SchedulerParams> {
+        #[cfg(target_os = "linux")]
+        if scheduler == Scheduler::SCHED_DEADLINE {
+            return Err(PreemptRtError::UnsupportedPriority(scheduler));
+        }
+
+        let normalized = match self {
+            Priority::Normalized(value) => value.clamp(0.0, 1.0),
+            Priority::Crossplatform(level) => level.normalized(),
+        };
+
+        let min = scheduler.priority_min()?;
+        let max = scheduler.priority_max()?;
+        let priority = (min as f32 + normalized * (max - min) as f32).round() as c_int;
+
+        Ok(SchedulerParams { priority })
+    }
+}
+
+impl IntoSchedParams for Priority {
+    fn into_sched_params(self, _scheduler: Scheduler) -> SchedParams {
+        SchedParams::Relative(self)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// The parameters to apply for a given [`Scheduler`]. [`SCHED_DEADLINE`](Scheduler::SCHED_DEADLINE)
+/// takes [`DeadlineParams`] via `sched_setattr`; every other scheduler takes the plain
+/// priority-only [`SchedulerParams`] via `sched_setscheduler`/`sched_setparam`. On macOS,
+/// [`TimeConstraintParams`] can be used instead to control the underlying Mach time-constraint
+/// policy directly rather than letting [`ParameterizedScheduler::set_on`] derive one from a
+/// priority.
+pub enum SchedParams {
+    /// Priority-only params, applied via `sched_setscheduler`/`sched_setparam`.
+    Standard(SchedulerParams),
+    /// A [`Priority`] relative to the chosen scheduler's valid range, resolved into
+    /// [`SchedulerParams`] by [`ParameterizedScheduler::set_on`] once the scheduler is known.
+    Relative(Priority),
+    /// `SCHED_DEADLINE` params, applied via `sched_setattr`.
+    Deadline(DeadlineParams),
+    /// macOS Mach time-constraint policy params, applied via `thread_policy_set`.
+    #[cfg(target_os = "macos")]
+    TimeConstraint(TimeConstraintParams),
+}
+
 pub trait IntoSchedParams {
-    fn into_sched_params(self) -> SchedulerParams;
+    /// `scheduler` is the policy these params are about to be applied to - used by params like
+    /// [`Priority`] that need to map onto its valid priority range.
+    fn into_sched_params(self, scheduler: Scheduler) -> SchedParams;
 }
 
-impl IntoSchedParams for SchedulerParams {
-    fn into_sched_params(self) -> SchedulerParams {
+impl IntoSchedParams for SchedParams {
+    fn into_sched_params(self, _scheduler: Scheduler) -> SchedParams {
         self
     }
 }
 
+impl IntoSchedParams for SchedulerParams {
+    fn into_sched_params(self, _scheduler: Scheduler) -> SchedParams {
+        SchedParams::Standard(self)
+    }
+}
+
 impl IntoSchedParams for i32 {
-    fn into_sched_params(self) -> SchedulerParams {
-        SchedulerParams {
+    fn into_sched_params(self, _scheduler: Scheduler) -> SchedParams {
+        SchedParams::Standard(SchedulerParams {
             priority: self as c_int,
-        }
+        })
     }
 }
 
 impl<T: IntoSchedParams> IntoSchedParams for Option<T> {
-    fn into_sched_params(self) -> SchedulerParams {
+    fn into_sched_params(self, scheduler: Scheduler) -> SchedParams {
         match self {
-            None => SchedulerParams { priority: 0 },
-            Some(param) => param.into_sched_params(),
+            None => SchedParams::Standard(SchedulerParams { priority: 0 }),
+            Some(param) => param.into_sched_params(scheduler),
         }
     }
 }
@@ -259,37 +526,96 @@ impl<T: IntoSchedParams> IntoSchedParams for Option<T> {
 #[derive(Debug, Clone)]
 pub struct ParameterizedScheduler {
     scheduler: Scheduler,
-    params: SchedulerParams,
+    params: SchedParams,
+    reset_on_fork: bool,
 }
 
 impl ParameterizedScheduler {
-    /// Apply this scheduler + params on the current thread, validating that its priority is
-    /// between the valid min & max values for the chosen scheduler.
-    #[cfg_attr(
-        any(target_os = "macos", target_os = "windows"),
-        allow(unused_variables)
-    )]
-    pub fn set_on(self, pid: Pid) -> RtResult<()> {
-        let priority = self.params.priority;
-        let max = self.scheduler.priority_max()?;
-        let min = self.scheduler.priority_min()?;
-        if priority > max {
-            Err(PriorityAboveMax(priority, max))
-        } else if priority < min {
-            Err(PriorityBelowMin(priority, min))
-        } else {
-            #[cfg(target_os = "linux")]
-            return set_scheduler(pid, self.scheduler, self.params);
+    /// OR `SCHED_RESET_ON_FORK` into the policy applied by [`ParameterizedScheduler::set_on`],
+    /// so that children forked from the thread revert to `SCHED_NORMAL` at nice `0` instead of
+    /// inheriting its realtime priority. Only takes effect on Linux, and only for priority-based
+    /// params: [`DeadlineParams`] are applied via `sched_setattr` without the flag.
+    pub fn reset_on_fork(mut self, reset_on_fork: bool) -> ParameterizedScheduler {
+        self.reset_on_fork = reset_on_fork;
+        self
+    }
+
+    /// Apply this scheduler + params to `pid`, validating that its priority (or,
+    /// for `SCHED_DEADLINE`, its runtime/deadline/period) is sound for the chosen scheduler.
+    ///
+    /// On macOS, `pid` must be [`Pid::current_thread`] - Mach's `mach_thread_self()` has no way
+    /// to target another thread, so this returns [`PreemptRtError::CannotTargetOtherThread`]
+    /// rather than silently applying the policy to the calling thread instead of `pid`.
+    #[cfg_attr(target_os = "windows", allow(unused_variables))]
+    pub fn set_on(mut self, pid: Pid) -> RtResult<()> {
+        if let SchedParams::Relative(priority) = self.params {
+            self.params = SchedParams::Standard(priority.resolve(self.scheduler)?);
+        }
+
+        match self.params {
+            SchedParams::Relative(_) => unreachable!("SchedParams::Relative is resolved above"),
+            SchedParams::Standard(params) => {
+                let priority = params.priority;
+                let max = self.scheduler.priority_max()?;
+                let min = self.scheduler.priority_min()?;
+                if priority > max {
+                    Err(PriorityAboveMax(priority, max))
+                } else if priority < min {
+                    Err(PriorityBelowMin(priority, min))
+                } else {
+                    #[cfg(target_os = "linux")]
+                    return set_scheduler(pid, self.scheduler, params, self.reset_on_fork);
+                    #[cfg(target_os = "macos")]
+                    {
+                        macos::require_current_thread(pid)?;
+                        return match self.scheduler {
+                            Scheduler::SCHED_FIFO | Scheduler::SCHED_RR => {
+                                set_time_constraint_policy(macos::time_constraint_from_priority(
+                                    priority, min, max,
+                                ))
+                            }
+                            _ => Err(PreemptRtError::NonLinuxPlatform("macos")),
+                        };
+                    }
+                    #[cfg(target_os = "windows")]
+                    return Err(PreemptRtError::NonLinuxPlatform("windows"));
+                }
+            }
+            SchedParams::Deadline(params) => {
+                params.validate()?;
+                #[cfg(target_os = "linux")]
+                return set_deadline_params(pid, params);
+                #[cfg(target_os = "macos")]
+                return Err(PreemptRtError::NonLinuxPlatform("macos"));
+                #[cfg(target_os = "windows")]
+                return Err(PreemptRtError::NonLinuxPlatform("windows"));
+            }
             #[cfg(target_os = "macos")]
-            return Err(PreemptRtError::NonLinuxPlatform("macos"));
-            #[cfg(target_os = "windows")]
-            return Err(PreemptRtError::NonLinuxPlatform("windows"));
+            SchedParams::TimeConstraint(params) => {
+                macos::require_current_thread(pid)?;
+                set_time_constraint_policy(params)
+            }
         }
     }
 
     pub fn set_current(self) -> RtResult<()> {
         self.set_on(Pid::current_thread())
     }
+
+    /// Apply this scheduler + params to every thread of `pid`, by enumerating its threads via
+    /// [`for_process`]. A failure on one thread is collected rather than aborting the rest, so
+    /// the caller can see exactly which threads succeeded and which didn't.
+    #[cfg(target_os = "linux")]
+    pub fn set_on_all_threads(self, pid: Pid) -> RtResult<Vec<(Pid, RtResult<()>)>> {
+        let tids = for_process(pid)?;
+        Ok(tids
+            .into_iter()
+            .map(|tid| {
+                let result = self.clone().set_on(tid);
+                (tid, result)
+            })
+            .collect())
+    }
 }
 
 #[cfg(target_os = "linux")]
@@ -297,11 +623,43 @@ mod linux {
     use super::*;
     use std::mem::MaybeUninit;
 
-    /// Get the current scheduler in use for a given process or thread.
+    /// Enumerate the thread ids (TIDs) of every thread in a process by reading
+    /// `/proc/<pid>/task/`, the same approach tools like `chrt` use to apply a policy to every
+    /// thread of a running process rather than just its main thread.
+    ///
+    /// Unlike the sched_* syscalls, `/proc` has no `0`-means-caller convention, so
+    /// `Pid::current_thread()` is resolved to the real pid via `getpid()` before building the
+    /// path.
+    pub fn for_process(pid: Pid) -> RtResult<Vec<Pid>> {
+        let raw_pid: libc::pid_t = pid.into();
+        let raw_pid = if raw_pid == 0 {
+            unsafe { libc::getpid() }
+        } else {
+            raw_pid
+        };
+
+        let entries = std::fs::read_dir(format!("/proc/{raw_pid}/task"))
+            .map_err(|e| PreemptRtError::Errno(e.raw_os_error().unwrap_or(libc::ENOENT)))?;
+
+        entries
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| entry.file_name().to_str()?.parse::<libc::pid_t>().ok())
+            .map(|tid| Ok(Pid::from_raw(tid)))
+            .collect()
+    }
+
+    /// Get the current scheduler in use for a given process or thread, along with whether
+    /// `SCHED_RESET_ON_FORK` is set on it. The bit is masked off before looking up the
+    /// `Scheduler`, since `Scheduler::try_from` has no `SCHED_RESET_ON_FORK` variant of its own -
+    /// without masking, round-tripping a thread's policy would spuriously fail with
+    /// `UnknownScheduler`.
     /// Using `Pid::from_raw(0)` will fetch the scheduler for the calling thread.
-    pub fn get_scheduler(pid: Pid) -> RtResult<Scheduler> {
+    pub fn get_scheduler(pid: Pid) -> RtResult<(Scheduler, bool)> {
         let res = unsafe { libc::sched_getscheduler(pid.into()) };
-        handle_errno(res).and_then(Scheduler::try_from)
+        let policy = handle_errno(res)?;
+        let reset_on_fork = policy & libc::SCHED_RESET_ON_FORK != 0;
+        let scheduler = Scheduler::try_from(policy & !libc::SCHED_RESET_ON_FORK)?;
+        Ok((scheduler, reset_on_fork))
     }
 
     /// Set the scheduler and parameters for a given process or thread.
@@ -312,10 +670,24 @@ mod linux {
     ///
     /// SCHED_FIFO and SCHED_RR allow priorities between the min and max inclusive.
     ///
-    /// SCHED_DEADLINE cannot be set with this function, `libc::sched_setattr` must be used instead.
-    pub fn set_scheduler(pid: Pid, scheduler: Scheduler, param: SchedulerParams) -> RtResult<()> {
+    /// SCHED_DEADLINE cannot be set with this function, use [`set_deadline_params`] instead,
+    /// which makes the `sched_setattr` syscall via `libc::syscall`.
+    ///
+    /// When `reset_on_fork` is set, `SCHED_RESET_ON_FORK` is OR'd into the policy so that
+    /// children forked from this thread revert to `SCHED_NORMAL` at nice `0` instead of
+    /// inheriting its realtime priority.
+    pub fn set_scheduler(
+        pid: Pid,
+        scheduler: Scheduler,
+        param: SchedulerParams,
+        reset_on_fork: bool,
+    ) -> RtResult<()> {
         let param: libc::sched_param = param.into();
-        let res = unsafe { libc::sched_setscheduler(pid.into(), scheduler as c_int, &param) };
+        let mut policy = scheduler as c_int;
+        if reset_on_fork {
+            policy |= libc::SCHED_RESET_ON_FORK;
+        }
+        let res = unsafe { libc::sched_setscheduler(pid.into(), policy, &param) };
 
         handle_errno(res).map(drop)
     }
@@ -338,7 +710,210 @@ mod linux {
         let res = unsafe { libc::sched_setparam(pid.into(), &param) };
         handle_errno(res).map(drop)
     }
+
+    #[repr(C)]
+    #[derive(Debug, Clone, Copy, Default)]
+    /// Mirrors the kernel's `struct sched_attr`, which carries the extra runtime/deadline/period
+    /// fields that `libc::sched_param` has no room for. Not exposed by the `libc` crate, so the
+    /// syscalls are made directly via `libc::syscall`.
+    struct SchedAttr {
+        size: u32,
+        sched_policy: u32,
+        sched_flags: u64,
+        sched_nice: i32,
+        sched_priority: u32,
+        sched_runtime: u64,
+        sched_deadline: u64,
+        sched_period: u64,
+    }
+
+    /// Set `SCHED_DEADLINE` parameters for a given process or thread via `sched_setattr`, since
+    /// `libc::sched_setscheduler`/`set_scheduler` can't carry the runtime/deadline/period fields.
+    /// Using `Pid::from_raw(0)` will set the parameters for the calling thread.
+    pub fn set_deadline_params(pid: Pid, params: DeadlineParams) -> RtResult<()> {
+        let attr = SchedAttr {
+            size: std::mem::size_of::<SchedAttr>() as u32,
+            sched_policy: libc::SCHED_DEADLINE as u32,
+            sched_runtime: params.runtime.as_nanos() as u64,
+            sched_deadline: params.deadline.as_nanos() as u64,
+            sched_period: params.period.as_nanos() as u64,
+            ..Default::default()
+        };
+
+        let res = unsafe {
+            libc::syscall(
+                libc::SYS_sched_setattr,
+                libc::pid_t::from(pid),
+                &attr as *const SchedAttr,
+                0u32,
+            )
+        };
+
+        handle_errno(res as c_int).map(drop)
+    }
+
+    /// Get the current `SCHED_DEADLINE` parameters for a given process or thread via
+    /// `sched_getattr`. Using `Pid::from_raw(0)` will return the parameters for the calling
+    /// thread.
+    pub fn get_deadline_params(pid: Pid) -> RtResult<DeadlineParams> {
+        let mut attr = SchedAttr {
+            size: std::mem::size_of::<SchedAttr>() as u32,
+            ..Default::default()
+        };
+
+        let res = unsafe {
+            libc::syscall(
+                libc::SYS_sched_getattr,
+                libc::pid_t::from(pid),
+                &mut attr as *mut SchedAttr,
+                std::mem::size_of::<SchedAttr>() as u32,
+                0u32,
+            )
+        };
+
+        handle_errno(res as c_int)?;
+
+        Ok(DeadlineParams {
+            runtime: Duration::from_nanos(attr.sched_runtime),
+            deadline: Duration::from_nanos(attr.sched_deadline),
+            period: Duration::from_nanos(attr.sched_period),
+        })
+    }
 }
 
 #[cfg(target_os = "linux")]
 pub use linux::*;
+
+#[cfg(target_os = "macos")]
+mod macos {
+    use super::*;
+
+    type MachPortT = u32;
+    type KernReturnT = i32;
+    type NaturalT = u32;
+    type IntegerT = i32;
+    type BooleanT = i32;
+    type MachMsgTypeNumberT = u32;
+    type ThreadPolicyFlavorT = i32;
+    type ThreadT = MachPortT;
+    type ThreadPolicyT = *mut IntegerT;
+
+    const KERN_SUCCESS: KernReturnT = 0;
+    /// `THREAD_TIME_CONSTRAINT_POLICY` from `<mach/thread_policy.h>`.
+    const THREAD_TIME_CONSTRAINT_POLICY: ThreadPolicyFlavorT = 2;
+    /// `sizeof(thread_time_constraint_policy_data_t) / sizeof(integer_t)`: the struct has 4
+    /// 4-byte fields, so this is always `4`.
+    const THREAD_TIME_CONSTRAINT_POLICY_COUNT: MachMsgTypeNumberT = 4;
+
+    /// `mach_thread_self()` always targets the calling thread - there's no Mach equivalent of
+    /// passing an arbitrary `pid`/`tid`. Reject anything other than
+    /// [`Pid::current_thread`] up front, rather than silently applying the policy to the
+    /// wrong thread.
+    pub(crate) fn require_current_thread(pid: Pid) -> RtResult<()> {
+        if pid == Pid::current_thread() {
+            Ok(())
+        } else {
+            Err(PreemptRtError::CannotTargetOtherThread(pid.into()))
+        }
+    }
+
+    #[repr(C)]
+    #[derive(Debug, Clone, Copy, Default)]
+    /// Mirrors Mach's `thread_time_constraint_policy_data_t`. Not exposed by the `libc` crate,
+    /// so the Mach calls are declared directly below.
+    struct ThreadTimeConstraintPolicy {
+        period: NaturalT,
+        computation: NaturalT,
+        constraint: NaturalT,
+        preemptible: BooleanT,
+    }
+
+    #[repr(C)]
+    #[derive(Debug, Clone, Copy, Default)]
+    /// Mirrors Mach's `mach_timebase_info_data_t`, used to convert nanoseconds to the absolute
+    /// time ticks Mach scheduling APIs expect.
+    struct MachTimebaseInfo {
+        numer: u32,
+        denom: u32,
+    }
+
+    extern "C" {
+        fn mach_thread_self() -> ThreadT;
+        fn mach_timebase_info(info: *mut MachTimebaseInfo) -> KernReturnT;
+        fn thread_policy_set(
+            thread: ThreadT,
+            flavor: ThreadPolicyFlavorT,
+            policy_info: ThreadPolicyT,
+            count: MachMsgTypeNumberT,
+        ) -> KernReturnT;
+        fn mach_port_deallocate(task: MachPortT, name: MachPortT) -> KernReturnT;
+        static mach_task_self_: MachPortT;
+    }
+
+    /// Convert a nanosecond duration into Mach absolute-time ticks via `mach_timebase_info`.
+    fn nanos_to_abs_ticks(nanos: u128) -> RtResult<u32> {
+        let mut timebase = MachTimebaseInfo::default();
+        let res = unsafe { mach_timebase_info(&mut timebase) };
+        if res != KERN_SUCCESS {
+            return Err(PreemptRtError::Errno(res));
+        }
+        let ticks = nanos * timebase.denom as u128 / timebase.numer as u128;
+        Ok(ticks as u32)
+    }
+
+    /// Translate a requested `sched_priority` into a nominal [`TimeConstraintParams`], for
+    /// callers that only supplied a [`SchedulerParams`] priority rather than an explicit
+    /// Mach time-constraint policy. Scales the computation time linearly with priority within
+    /// a fixed 10ms nominal period - threads at `max` priority get the whole period and are
+    /// non-preemptible, everything else gets a proportional share (never less than 5% of the
+    /// period) and stays preemptible.
+    pub(crate) fn time_constraint_from_priority(
+        priority: c_int,
+        min: c_int,
+        max: c_int,
+    ) -> TimeConstraintParams {
+        let nominal_period = Duration::from_millis(10);
+
+        let span = (max - min).max(1) as f64;
+        let fraction = ((priority - min) as f64 / span).clamp(0.0, 1.0);
+        let computation = nominal_period.mul_f64(fraction.max(0.05));
+
+        TimeConstraintParams {
+            period: nominal_period,
+            computation,
+            constraint: nominal_period,
+            preemptible: priority < max,
+        }
+    }
+
+    /// Apply `THREAD_TIME_CONSTRAINT_POLICY` to the current Mach thread via `thread_policy_set`,
+    /// the closest macOS equivalent of Linux's SCHED_FIFO/SCHED_RR realtime scheduling.
+    pub fn set_time_constraint_policy(params: TimeConstraintParams) -> RtResult<()> {
+        let mut policy = ThreadTimeConstraintPolicy {
+            period: nanos_to_abs_ticks(params.period.as_nanos())?,
+            computation: nanos_to_abs_ticks(params.computation.as_nanos())?,
+            constraint: nanos_to_abs_ticks(params.constraint.as_nanos())?,
+            preemptible: params.preemptible as BooleanT,
+        };
+
+        let thread = unsafe { mach_thread_self() };
+        let res = unsafe {
+            thread_policy_set(
+                thread,
+                THREAD_TIME_CONSTRAINT_POLICY,
+                &mut policy as *mut ThreadTimeConstraintPolicy as ThreadPolicyT,
+                THREAD_TIME_CONSTRAINT_POLICY_COUNT,
+            )
+        };
+        unsafe { mach_port_deallocate(mach_task_self_, thread) };
+
+        if res == KERN_SUCCESS {
+            Ok(())
+        } else {
+            Err(PreemptRtError::Errno(res))
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+pub use macos::*;
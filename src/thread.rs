@@ -1,4 +1,4 @@
-use crate::sched::{IntoSchedParams, ParameterizedScheduler, Scheduler};
+use crate::sched::{self, IntoSchedParams, ParameterizedScheduler, Pid, Scheduler};
 use std::thread;
 
 #[must_use = "must eventually spawn the thread"]
@@ -7,6 +7,7 @@ pub struct Builder {
     name: Option<String>,
     stack_size: Option<usize>,
     parameterized_scheduler: ParameterizedScheduler,
+    nice: Option<i32>,
 }
 
 impl Builder {
@@ -14,7 +15,8 @@ impl Builder {
         Builder {
             name: None,
             stack_size: None,
-            parameterized_scheduler: scheduler.with_params(params.into_sched_params()),
+            parameterized_scheduler: scheduler.with_params(params.into_sched_params(scheduler)),
+            nice: None,
         }
     }
 
@@ -28,6 +30,24 @@ impl Builder {
         self
     }
 
+    /// OR `SCHED_RESET_ON_FORK` into the policy applied to the spawned thread, so that threads
+    /// it forks revert to `SCHED_NORMAL` at nice `0` instead of inheriting its realtime priority.
+    /// Only takes effect on Linux, and only for priority-based params: `DeadlineParams` are
+    /// applied without the flag.
+    pub fn reset_on_fork(mut self, reset_on_fork: bool) -> Builder {
+        self.parameterized_scheduler = self.parameterized_scheduler.reset_on_fork(reset_on_fork);
+        self
+    }
+
+    /// Set the nice value for the spawned thread via `setpriority`. This is the only way to
+    /// influence scheduling for `SCHED_NORMAL`, `SCHED_BATCH` and `SCHED_IDLE`, whose
+    /// `sched_priority` must be `0` - pairing `SCHED_BATCH` with a positive nice value is a
+    /// good way to de-prioritize a CPU-bound background thread.
+    pub fn nice(mut self, nice: i32) -> Builder {
+        self.nice = Some(nice);
+        self
+    }
+
     pub fn try_spawn<F, T>(self, f: F) -> thread::JoinHandle<T>
     where
         F: FnOnce(crate::sched::RtResult<()>) -> T + Send + 'static,
@@ -43,8 +63,13 @@ impl Builder {
             tb = tb.stack_size(stack_size);
         }
 
-        tb.spawn(|| {
-            let sched_result = self.parameterized_scheduler.set_current();
+        tb.spawn(move || {
+            let sched_result = self.parameterized_scheduler.set_current().and_then(|()| {
+                match self.nice {
+                    Some(nice) => sched::set_nice(Pid::current_thread(), nice),
+                    None => Ok(()),
+                }
+            });
             f(sched_result)
         })
         .expect("failed to spawn thread")
@@ -53,7 +78,8 @@ impl Builder {
 
 /// Spawn a thread with the provided scheduler and params.
 ///
-/// Params can either be a SchedParams struct, or an i32 representing the desired priority.
+/// Params can be a SchedulerParams struct, an i32 representing the desired priority, or a
+/// Priority for a scheduler-relative value.
 /// This function validates that the priority is between min and max for the scheduler before
 /// attempting to set it. It panics if the priority is outside the allowed range or setting the
 /// scheduler returns an error code.
@@ -80,7 +106,8 @@ where
 /// Spawn a thread and attempt to set the schedule of the current thread. The result of setting
 /// the scheduler is provided to the thread closure as an argument.
 ///
-/// Params can either be a SchedParams struct, or an i32 representing the desired priority.
+/// Params can be a SchedulerParams struct, an i32 representing the desired priority, or a
+/// Priority for a scheduler-relative value.
 /// This function validates that the priority is between min and max for the scheduler before
 /// attempting to set it. Failures will continue execution and pass through the Result to the
 /// thread closure.